@@ -26,9 +26,27 @@ pub struct ImportDecl {
 impl ImportDecl {
     pub fn import_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![import]) }
     pub fn imports(&self) -> AstChildren<ImportClause> { support::children(&self.syntax) }
+    pub fn attributes(&self) -> Option<ImportAttributes> { support::child(&self.syntax) }
     pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![;]) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportAttributes {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ImportAttributes {
+    pub fn with_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![with]) }
+    pub fn l_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['{']) }
+    pub fn attributes(&self) -> AstChildren<ImportAttribute> { support::children(&self.syntax) }
+    pub fn r_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['}']) }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ImportAttribute {
+    pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![:]) }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WildcardImport {
     pub(crate) syntax: SyntaxNode,
 }
@@ -66,6 +84,7 @@ impl ExportNamed {
     pub fn l_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['{']) }
     pub fn specifiers(&self) -> AstChildren<Specifier> { support::children(&self.syntax) }
     pub fn r_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['}']) }
+    pub fn attributes(&self) -> Option<ImportAttributes> { support::child(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExportWildcard {
@@ -74,6 +93,7 @@ pub struct ExportWildcard {
 impl ExportWildcard {
     pub fn export_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![export]) }
     pub fn star_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![*]) }
+    pub fn attributes(&self) -> Option<ImportAttributes> { support::child(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExportDefaultDecl {
@@ -887,6 +907,28 @@ impl AstNode for ImportDecl {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for ImportAttributes {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == IMPORT_ATTRIBUTES }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for ImportAttribute {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == IMPORT_ATTRIBUTE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for WildcardImport {
     fn can_cast(kind: SyntaxKind) -> bool { kind == WILDCARD_IMPORT }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -2363,6 +2405,16 @@ impl std::fmt::Display for ImportDecl {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for ImportAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ImportAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for WildcardImport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -279,6 +279,30 @@ impl Specifier {
     }
 }
 
+impl ImportAttribute {
+    /// The key of the attribute, either a name or a string literal, such as `type` in `with { type: "json" }`
+    pub fn key(&self) -> Option<PropName> {
+        let colon = self.colon_token().map(|tok| tok.text_range().start());
+        self.syntax()
+            .children()
+            .filter(|x| matches!(x.kind(), NAME | LITERAL))
+            .find(|x| match colon {
+                Some(colon) => x.text_range().end() <= colon,
+                None => true,
+            })
+            .and_then(|x| x.try_to())
+    }
+
+    /// The string value of the attribute, such as `"json"` in `with { type: "json" }`
+    pub fn value(&self) -> Option<Literal> {
+        let colon = self.colon_token()?.text_range().end();
+        self.syntax()
+            .children()
+            .filter(|x| x.text_range().start() >= colon)
+            .find_map(|x| x.try_to::<Literal>())
+    }
+}
+
 impl WildcardImport {
     pub fn as_token(&self) -> Option<SyntaxToken> {
         self.syntax()
@@ -126,6 +126,15 @@ fn is_valid_target(p: &mut Parser, target: Expr) -> bool {
 // [foo, bar] = baz;
 // ({ bar, baz } = {});
 // ({ bar: [baz], foo } = {});
+
+// test_err optional_chain_assign
+// a.b?.c = 1;
+// a?.[b] += 1;
+// a.b?.c++;
+// --a?.b;
+// (a?.b) = 1;
+// for (a?.b of c);
+// for (a?.b in c);
 fn assign_expr_recursive(
     p: &mut Parser,
     mut target: CompletedMarker,
@@ -134,12 +143,10 @@ fn assign_expr_recursive(
 ) -> Option<CompletedMarker> {
     // TODO: dont always reparse as pattern since it will yield wonky errors for `(foo = true) = bar`
     if p.at_ts(ASSIGN_TOKENS) {
-        if p.at(T![=]) {
-            if !is_valid_target(p, p.parse_marker(&target)) {
-                p.rewind(token_cur);
-                p.drain_events(p.cur_event_pos() - event_cur);
-                target = pattern(p)?;
-            }
+        if p.at(T![=]) && !is_valid_target(p, p.parse_marker(&target)) {
+            p.rewind(token_cur);
+            p.drain_events(p.cur_event_pos() - event_cur);
+            target = pattern(p)?;
         } else {
             check_simple_assign_target(p, &p.parse_marker(&target), target.range(p));
         }
@@ -376,7 +383,20 @@ pub fn member_or_new_expr(p: &mut Parser, new_expr: bool) -> Option<CompletedMar
             return Some(subscripts(p, complete, true));
         }
 
-        member_or_new_expr(p, new_expr)?;
+        // test_err new_optional_chain
+        // new a?.b();
+        // new a.b?.c();
+        // new a?.b;
+        // new #
+        let callee = member_or_new_expr(p, new_expr)?;
+        // the callee may be an error node from recovery which is not an expression
+        if Expr::can_cast(callee.kind()) && is_optional_chain(&p.parse_marker(&callee)) {
+            let err = p
+                .err_builder("Invalid optional chain in a `new` callee")
+                .primary(callee.range(p), "");
+
+            p.error(err);
+        }
 
         if !new_expr || p.at(T!['(']) {
             args(p);
@@ -435,18 +455,36 @@ pub fn subscripts(p: &mut Parser, lhs: CompletedMarker, no_call: bool) -> Comple
             }
             T!['['] => lhs = bracket_expr(p, lhs, false),
             T![.] => lhs = dot_expr(p, lhs, false),
-            BACKTICK => lhs = template(p, Some(lhs)),
+            T![?.] => lhs = optional_chain(p, lhs),
+            // test_err optional_chain_tagged_template
+            // a?.b`c`
+            // foo()?.b`c`
+            // a?.b.c`x`
+            BACKTICK => {
+                let in_chain =
+                    Expr::can_cast(lhs.kind()) && is_optional_chain(&p.parse_marker(&lhs));
+                lhs = template(p, Some(lhs));
+                if in_chain {
+                    let err = p
+                        .err_builder("Tagged templates cannot be used in optional chains")
+                        .primary(lhs.range(p), "");
+
+                    p.error(err);
+                }
+            }
             _ => return lhs,
         }
     }
     lhs
 }
 
-// An optional chain such as `foo?.bar?.(baz)?.[foo]`
+/// An optional chain such as `foo?.bar?.(baz)?.[foo]`
 // test optional_chain
 // foo?.bar?.(baz)?.[foo]
 // foo.bar?.(f).baz
 // foo[bar]?.baz
+// foo()?.bar
+// arr[0]?.method(x)
 pub fn optional_chain(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     let mut lhs = lhs;
     while !p.at(EOF) {
@@ -730,6 +768,11 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
             //  constructor() {}
             // }
             // foo[class {}]
+
+            // test class_expr_heritage
+            // let a = class extends Base {};
+            // let b = class B extends foo.Bar {};
+            // let c = class extends (a, b) {};
             let mut m = class_decl(p, true);
             m.change_kind(p, CLASS_EXPR);
             m
@@ -857,6 +900,10 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 
                 // test import_call
                 // import("foo")
+
+                // test import_call_member
+                // const p = import("m").then(f);
+                // import("m")?.default;
                 p.expect(T!['(']);
                 assign_expr(p);
                 p.expect(T![')']);
@@ -1,10 +1,11 @@
 //! Top level functions for parsing a script or module, also includes module specific items.
 
 use super::decl::{class_decl, function_decl};
-use super::expr::assign_expr;
+use super::expr::{assign_expr, identifier_name, literal, template};
 use super::pat::binding_identifier;
 use super::stmt::{block_items, semi, var_decl, STMT_RECOVERY_SET};
 use crate::{SyntaxKind::*, *};
+use std::collections::HashMap;
 
 /// Parse an ECMAScript script.
 ///
@@ -75,10 +76,136 @@ pub fn import_decl(p: &mut Parser) -> CompletedMarker {
         }
     }
 
+    if p.at(T![with]) {
+        import_attributes(p);
+    }
+
     p.expect(T![;]);
     m.complete(p, IMPORT_DECL)
 }
 
+/// An import attributes clause such as `with { type: "json" }`
+// test import_attributes
+// import a from "a.json" with { type: "json" };
+// import "b.json" with { "type": "json", };
+// import * as c from "c" with {};
+// import { d } from "d" with { type: "json", if: "e" };
+fn import_attributes(p: &mut Parser) -> CompletedMarker {
+    // test_err import_attributes_err
+    // import a from "a" with { type: json };
+    // import b from "b" with { 5: "json", type "json" };
+    // import c from "c" with { : "json" };
+    // import d from "d" with { type: `json` };
+    // import e from "e" with { type: "json", type: "json" };
+    // import f from "f" with { "a": "b", a: "c" };
+
+    // test_err import_attributes_recovery
+    // import a from "a" with;
+    // let b = 1;
+    // import c from "c" with { type: "json"
+    // foo();
+    // import d from "d" with { if: "e", var x = 5;
+    let m = p.start();
+    p.expect(T![with]);
+    if !p.expect(T!['{']) {
+        return m.complete(p, IMPORT_ATTRIBUTES);
+    }
+    let mut keys = HashMap::new();
+    let mut first = true;
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if first {
+            first = false;
+        } else if p.at(T![,]) && p.nth_at(1, T!['}']) {
+            p.bump_any();
+            break;
+        } else if !p.at(T![,]) {
+            // A missing separator most likely means an unclosed clause,
+            // bail so we don't swallow the next statement
+            break;
+        } else {
+            p.bump_any();
+        }
+
+        let can_start_key = matches!(p.cur(), STRING | T![ident] | T![:]) || p.cur().is_keyword();
+        if !can_start_key || (p.at_ts(STMT_RECOVERY_SET) && !p.nth_at(1, T![:])) {
+            break;
+        }
+        let attr = import_attribute(p);
+        check_duplicate_attribute(p, &attr, &mut keys);
+    }
+    p.expect(T!['}']);
+    m.complete(p, IMPORT_ATTRIBUTES)
+}
+
+fn import_attribute(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    match p.cur() {
+        STRING => {
+            literal(p);
+        }
+        t if t.is_keyword() || t == T![ident] => {
+            identifier_name(p);
+        }
+        _ => {
+            let err = p
+                .err_builder("Expected an identifier or string for an import attribute key")
+                .primary(p.cur_tok(), "");
+
+            p.err_recover(err, token_set![T![:], T![,], T!['}']], false);
+        }
+    }
+
+    p.expect(T![:]);
+
+    if p.at(STRING) {
+        literal(p);
+    } else {
+        let err = p
+            .err_builder("Expected a string literal for the value of an import attribute")
+            .primary(p.cur_tok(), "");
+
+        if p.at(BACKTICK) {
+            // A template spans multiple tokens, recovering past just the
+            // backtick would leave the rest of it behind
+            let m = p.start();
+            p.error(err);
+            template(p, None);
+            m.complete(p, ERROR);
+        } else {
+            p.err_recover(err, token_set![T![,], T!['}']], false);
+        }
+    }
+    m.complete(p, IMPORT_ATTRIBUTE)
+}
+
+fn check_duplicate_attribute(
+    p: &mut Parser,
+    marker: &CompletedMarker,
+    keys: &mut HashMap<String, TextRange>,
+) {
+    let key = match p.parse_marker::<ast::ImportAttribute>(marker).key() {
+        Some(key) => key,
+        None => return,
+    };
+    let range = marker.offset_range(p, key.syntax().trimmed_range());
+    let text = key.syntax().text().to_string();
+    let name = match key {
+        ast::PropName::Literal(_) => text.get(1..text.len() - 1).unwrap_or_default(),
+        _ => &text,
+    };
+
+    if let Some(entry) = keys.get(name) {
+        let err = p
+            .err_builder("Import attributes may not have duplicate keys")
+            .secondary(*entry, format!("`{}` is first used here", name))
+            .primary(range, format!("a second `{}` key is not allowed", name));
+
+        p.error(err);
+    } else {
+        keys.insert(name.to_owned(), range);
+    }
+}
+
 fn wildcard(p: &mut Parser, m: impl Into<Option<Marker>>) -> Marker {
     let m = m.into().unwrap_or_else(|| p.start());
     p.bump_any();
@@ -140,6 +267,8 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 
     if p.eat(T![default]) {
         let complete = match p.cur() {
+            // test export_default_function
+            // export default function() {}
             T![function] => {
                 let inner = p.start();
                 function_decl(p, inner, true);
@@ -196,11 +325,24 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
                 function_decl(p, inner, true);
                 m.complete(p, EXPORT_DECL)
             }
+            // test export_from_attributes
+            // export { a as b } from "a.json" with { type: "json" };
+            // export * as c from "c.json" with { type: "json" };
+
+            // test_err export_from_attributes_err
+            // export * from "m" with;
+            // let a = 1;
+            // export * from "a" with { type: `json` };
+            // export { b } from "b" with { type: "json"
+            // foo();
             T!['{'] => {
                 let start_marker = p.start();
                 let inner = named_list(p, start_marker);
                 if p.cur_src() == "from" {
                     from_clause(p);
+                    if p.at(T![with]) {
+                        import_attributes(p);
+                    }
                 }
                 inner.complete(p, EXPORT_NAMED)
             }
@@ -208,6 +350,9 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
                 let start_marker = p.start();
                 let inner = wildcard(p, start_marker);
                 from_clause(p);
+                if p.at(T![with]) {
+                    import_attributes(p);
+                }
                 semi(p, start..p.cur_tok().range.start);
                 inner.complete(p, EXPORT_WILDCARD)
             }
@@ -541,6 +541,9 @@ pub fn var_decl(p: &mut Parser, no_semi: bool) -> CompletedMarker {
     // test_err var_decl_err
     // var a =;
     // const a = 5 let b = 5;
+
+    // test_err var_decl_type_annotation
+    // let x: number = 5;
     let m = p.start();
     let start = p.cur_tok().range.start;
     let mut is_const = None;
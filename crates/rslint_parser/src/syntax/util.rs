@@ -20,6 +20,7 @@ pub fn check_simple_assign_target(p: &mut Parser, target: &Expr, range: TextRang
         .primary(range, "This expression cannot be assigned to");
 
     match target.syntax().kind() {
+        BRACKET_EXPR | DOT_EXPR if is_optional_chain(target) => p.error(err),
         NAME_REF | BRACKET_EXPR | DOT_EXPR => {}
         GROUPING_EXPR => {
             let inner = GroupingExpr::cast(target.syntax().to_owned())
@@ -33,6 +34,25 @@ pub fn check_simple_assign_target(p: &mut Parser, target: &Expr, range: TextRang
     }
 }
 
+/// Check if an expression is an optional chain such as `foo?.bar.baz`.
+///
+/// Parentheses end a chain, therefore `(foo?.bar).baz` is not one.
+pub fn is_optional_chain(expr: &Expr) -> bool {
+    let mut cur = Some(expr.to_owned());
+    while let Some(expr) = cur {
+        if expr.opt_chain() {
+            return true;
+        }
+        cur = match expr {
+            Expr::DotExpr(expr) => expr.object(),
+            Expr::BracketExpr(expr) => expr.object(),
+            Expr::CallExpr(expr) => expr.callee(),
+            _ => None,
+        };
+    }
+    false
+}
+
 /// Check if the use of a statement label is valid and the label is defined.
 ///
 /// # Panics
@@ -193,7 +213,10 @@ fn check_name_pat(
 pub fn check_for_stmt_lhs(p: &mut Parser, expr: Expr, marker: &CompletedMarker) {
     match expr {
         Expr::NameRef(ident) => check_simple_assign_target(p, &Expr::from(ident), marker.range(p)),
-        Expr::DotExpr(_) | Expr::BracketExpr(_) => {}
+        Expr::DotExpr(_) | Expr::BracketExpr(_) => {
+            let range = marker.offset_range(p, expr.syntax().trimmed_range());
+            check_simple_assign_target(p, &expr, range)
+        }
         Expr::AssignExpr(expr) => {
             if let Some(rhs) = expr.rhs() {
                 check_for_stmt_lhs(p, rhs, marker);
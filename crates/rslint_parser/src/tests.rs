@@ -14,6 +14,40 @@ fn parser_smoke_test() {
     assert!(parse_module(src, 0).ok().is_ok());
 }
 
+#[test]
+fn import_attribute_accessors() {
+    use crate::ast::{ImportDecl, PropName};
+    use crate::AstNode;
+
+    let attributes = |src: &str| {
+        let parse = parse_module(src, 0);
+        let decl = parse
+            .syntax()
+            .descendants()
+            .find_map(ImportDecl::cast)
+            .unwrap();
+        decl.attributes().unwrap().attributes().collect::<Vec<_>>()
+    };
+
+    let attrs = attributes(r#"import a from "a" with { type: "json", "b": "c" };"#);
+    assert!(
+        matches!(attrs[0].key(), Some(PropName::Ident(name)) if name.syntax().text() == "type")
+    );
+    assert_eq!(attrs[0].value().unwrap().syntax().text(), r#""json""#);
+    assert!(
+        matches!(attrs[1].key(), Some(PropName::Literal(lit)) if lit.syntax().text() == r#""b""#)
+    );
+    assert_eq!(attrs[1].value().unwrap().syntax().text(), r#""c""#);
+
+    let attrs = attributes(r#"import a from "a" with { : "json" };"#);
+    assert!(attrs[0].key().is_none());
+    assert_eq!(attrs[0].value().unwrap().syntax().text(), r#""json""#);
+
+    let attrs = attributes(r#"import a from "a" with { type: json };"#);
+    assert!(attrs[0].key().is_some());
+    assert!(attrs[0].value().is_none());
+}
+
 fn test_data_dir() -> PathBuf {
     project_dir().join("rslint_parser/test_data")
 }
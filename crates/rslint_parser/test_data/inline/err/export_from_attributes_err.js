@@ -0,0 +1,5 @@
+export * from "m" with;
+let a = 1;
+export * from "a" with { type: `json` };
+export { b } from "b" with { type: "json"
+foo();
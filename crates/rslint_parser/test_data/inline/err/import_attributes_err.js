@@ -0,0 +1,6 @@
+import a from "a" with { type: json };
+import b from "b" with { 5: "json", type "json" };
+import c from "c" with { : "json" };
+import d from "d" with { type: `json` };
+import e from "e" with { type: "json", type: "json" };
+import f from "f" with { "a": "b", a: "c" };
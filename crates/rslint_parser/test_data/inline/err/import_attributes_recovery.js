@@ -0,0 +1,5 @@
+import a from "a" with;
+let b = 1;
+import c from "c" with { type: "json"
+foo();
+import d from "d" with { if: "e", var x = 5;
@@ -0,0 +1,4 @@
+new a?.b();
+new a.b?.c();
+new a?.b;
+new #
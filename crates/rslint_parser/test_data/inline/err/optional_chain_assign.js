@@ -0,0 +1,7 @@
+a.b?.c = 1;
+a?.[b] += 1;
+a.b?.c++;
+--a?.b;
+(a?.b) = 1;
+for (a?.b of c);
+for (a?.b in c);
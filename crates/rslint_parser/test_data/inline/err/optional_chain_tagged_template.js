@@ -0,0 +1,3 @@
+a?.b`c`
+foo()?.b`c`
+a?.b.c`x`
@@ -0,0 +1,3 @@
+let a = class extends Base {};
+let b = class B extends foo.Bar {};
+let c = class extends (a, b) {};
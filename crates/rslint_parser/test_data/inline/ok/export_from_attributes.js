@@ -0,0 +1,2 @@
+export { a as b } from "a.json" with { type: "json" };
+export * as c from "c.json" with { type: "json" };
@@ -0,0 +1,4 @@
+import a from "a.json" with { type: "json" };
+import "b.json" with { "type": "json", };
+import * as c from "c" with {};
+import { d } from "d" with { type: "json", if: "e" };
@@ -0,0 +1,2 @@
+const p = import("m").then(f);
+import("m")?.default;
@@ -0,0 +1,5 @@
+foo?.bar?.(baz)?.[foo]
+foo.bar?.(f).baz
+foo[bar]?.baz
+foo()?.bar
+arr[0]?.method(x)
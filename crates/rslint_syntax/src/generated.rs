@@ -206,6 +206,8 @@ pub enum SyntaxKind {
     WILDCARD_IMPORT,
     NAMED_IMPORTS,
     SPECIFIER,
+    IMPORT_ATTRIBUTES,
+    IMPORT_ATTRIBUTE,
     AWAIT_EXPR,
     FOR_STMT_TEST,
     FOR_STMT_UPDATE,
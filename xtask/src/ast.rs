@@ -213,6 +213,8 @@ pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
         "WILDCARD_IMPORT",
         "NAMED_IMPORTS",
         "SPECIFIER",
+        "IMPORT_ATTRIBUTES",
+        "IMPORT_ATTRIBUTE",
         "AWAIT_EXPR",
         // These three are just hacks for converting to ast node without
         // having to handle every error recovery case.
@@ -318,9 +320,23 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             imports: [ImportClause],
             /* from */
             /* source */
+            attributes: ImportAttributes,
             T![;]
         }
 
+        struct ImportAttributes {
+            T![with],
+            T!['{'],
+            attributes: [ImportAttribute],
+            T!['}']
+        }
+
+        struct ImportAttribute {
+            /* key */
+            T![:],
+            /* value */
+        }
+
         struct WildcardImport {
             T![*],
             /* as */
@@ -349,6 +365,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             T!['}'],
             /* from */
             /* source */
+            attributes: ImportAttributes,
         }
 
         struct ExportWildcard {
@@ -358,6 +375,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             /* name */
             /* from */
             /* source */
+            attributes: ImportAttributes,
         }
 
         struct ExportDefaultDecl {